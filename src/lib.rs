@@ -38,9 +38,12 @@
 //!
 //!   6. `Extent` does not implement `Iterator`, but it has an `iter` method
 //!      that copies `Extent` into `ExtentIter`, which does implement
-//!      `Iterator`.
+//!      `Iterator` and `DoubleEndedIterator`, the way `RangeInclusive` does:
+//!      `next` pulls from the low end and `next_back` pulls from the high
+//!      end, meeting in the middle on the final shared element.
 //!
-//!   7. There is also an `ExtentRevIter` that counts down.
+//!   7. There is also an `ExtentRevIter` that counts down, for symmetry with
+//!      `rev()` on the builtin range types.
 //!
 //!   8. Some basic set-like operators are provided (union, intersection,
 //!      contains) but nothing too fancy.
@@ -51,10 +54,11 @@
 
 use std::{
     borrow::Borrow,
+    iter::FusedIterator,
     ops::{Range, RangeInclusive},
 };
 
-use num_traits::PrimInt;
+use num_traits::{NumCast, PrimInt};
 
 #[derive(Copy, Clone, Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct Extent<N: PrimInt> {
@@ -147,7 +151,16 @@ impl<N: PrimInt> Extent<N> {
             Extent::empty()
         } else {
             let other = *other.borrow();
-            Self::new(&self.lo.max(other.lo), &self.hi.min(other.hi))
+            let lo = self.lo.max(other.lo);
+            let hi = self.hi.min(other.hi);
+            // Unlike `new`, which swaps out-of-order bounds, a disjoint
+            // pair here must collapse to `empty()` rather than be
+            // reinterpreted as the (nonsensical) range between them.
+            if lo > hi {
+                Self::empty()
+            } else {
+                Self { lo, hi }
+            }
         }
     }
 
@@ -156,9 +169,84 @@ impl<N: PrimInt> Extent<N> {
         self.lo <= n && n <= self.hi
     }
 
+    pub fn is_disjoint<S: Borrow<Self>>(&self, other: S) -> bool {
+        self.intersect(other).is_empty()
+    }
+
+    pub fn is_subset<S: Borrow<Self>>(&self, other: S) -> bool {
+        let other = *other.borrow();
+        self.is_empty() || (other.lo <= self.lo && self.hi <= other.hi)
+    }
+
+    /// Remove `other`'s elements from `self`, returning the (up to two)
+    /// pieces of `self` that remain as `(left, right)`. Either or both
+    /// pieces may be `Extent::empty()`: both empty means `other` covers
+    /// all of `self`, one empty means `other` overlapped only one edge
+    /// (or did not overlap `self` at all, giving `(self, empty)`), and
+    /// neither empty means `other` carved a hole out of the middle.
+    pub fn difference<S: Borrow<Self>>(&self, other: S) -> (Self, Self) {
+        let overlap = self.intersect(other);
+        if self.is_empty() || overlap.is_empty() {
+            return (*self, Self::empty());
+        }
+        let left = if overlap.lo == N::min_value() {
+            Self::empty()
+        } else {
+            // SAFETY: `self.lo` and `overlap.lo - 1` are already in the
+            // right order (or not, in which case this normalizes to
+            // empty); `overlap` is contained in `self` by construction.
+            unsafe { Self::new_unchecked(self.lo, overlap.lo - N::one()) }
+        };
+        let right = if overlap.hi == N::max_value() {
+            Self::empty()
+        } else {
+            // SAFETY: see above.
+            unsafe { Self::new_unchecked(overlap.hi + N::one(), self.hi) }
+        };
+        (left, right)
+    }
+
+    /// The elements in exactly one of `self` or `other`, as the (up to
+    /// two) pieces left over once their shared middle is removed from
+    /// each side. Note `self.union(other)` would over-approximate across
+    /// any gap between disjoint extents, so this is built from the two
+    /// one-sided differences instead.
+    pub fn symmetric_difference<S: Borrow<Self>>(&self, other: S) -> (Self, Self) {
+        let other = *other.borrow();
+        let overlap = self.intersect(other);
+        if overlap.is_empty() {
+            // self and other don't overlap (including when either is
+            // itself empty): the symmetric difference is just the two
+            // extents, ordered by position.
+            if self.is_empty() {
+                (other, Self::empty())
+            } else if other.is_empty() {
+                (*self, Self::empty())
+            } else if self.lo < other.lo {
+                (*self, other)
+            } else {
+                (other, *self)
+            }
+        } else {
+            let (a_left, a_right) = self.difference(other);
+            let (b_left, b_right) = other.difference(*self);
+            let left = if !a_left.is_empty() { a_left } else { b_left };
+            let right = if !a_right.is_empty() { a_right } else { b_right };
+            (left, right)
+        }
+    }
+
     pub fn iter(&self) -> ExtentIter<N> {
         ExtentIter(*self)
     }
+
+    /// Iterate `self` in strides of `step`, i.e. `lo, lo+step, lo+2*step,
+    /// ...` up to and including any value `<= hi`. Panics if `step` is
+    /// zero, like `std`'s `Iterator::step_by`.
+    pub fn step_by(self, step: N) -> ExtentStepIter<N> {
+        assert!(step != N::zero(), "Extent::step_by: step must not be zero");
+        ExtentStepIter { extent: self, step }
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -170,12 +258,115 @@ impl<N: PrimInt> Iterator for ExtentIter<N> {
     fn next(&mut self) -> Option<Self::Item> {
         if self.0.is_empty() {
             None
+        } else if self.0.lo == self.0.hi {
+            let v = self.0.lo;
+            self.0 = Extent::empty();
+            Some(v)
         } else {
             let v = self.0.lo;
             self.0.lo = self.0.lo + N::one();
             Some(v)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.0.is_empty() {
+            return (0, Some(0));
+        }
+        // `Extent::len()` computes `1 + (hi - lo)`, which overflows for a
+        // full-width extent. For a signed `N`, `hi - lo` itself overflows
+        // once the extent straddles zero widely enough; that span is then
+        // counted in `usize` split at zero, as `hi + (-(lo + 1)) + 1`,
+        // which can't overflow `N` even at `N::min_value()`. The 1 for
+        // the element count is likewise added only in `usize`.
+        let span = match self.0.hi.checked_sub(&self.0.lo) {
+            Some(span) => span.to_usize(),
+            None => match (
+                (N::zero() - (self.0.lo + N::one())).to_usize(),
+                self.0.hi.to_usize(),
+            ) {
+                (Some(below), Some(above)) => below
+                    .checked_add(above)
+                    .and_then(|span| span.checked_add(1)),
+                _ => None,
+            },
+        };
+        match span.and_then(|span| span.checked_add(1)) {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.0.hi()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+        match NumCast::from(n).and_then(|n: N| self.0.lo.checked_add(&n)) {
+            Some(v) if v <= self.0.hi => {
+                if v == self.0.hi {
+                    self.0 = Extent::empty();
+                } else {
+                    self.0.lo = v + N::one();
+                }
+                Some(v)
+            }
+            _ => {
+                self.0 = Extent::empty();
+                None
+            }
+        }
+    }
+}
+
+// No `ExactSizeIterator` impl: its `len()` must fit `usize` for every
+// valid extent, but `size_hint` can only report `(usize::MAX, None)`
+// once `N`'s own range exceeds `usize` (e.g. a full `u64` extent on a
+// 32- or 64-bit platform), and `ExactSizeIterator::len()`'s default
+// implementation panics in exactly that case.
+impl<N: PrimInt> FusedIterator for ExtentIter<N> {}
+
+impl<N: PrimInt> DoubleEndedIterator for ExtentIter<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            None
+        } else if self.0.lo == self.0.hi {
+            let v = self.0.hi;
+            self.0 = Extent::empty();
+            Some(v)
+        } else {
+            let v = self.0.hi;
+            self.0.hi = self.0.hi - N::one();
+            Some(v)
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+        match NumCast::from(n).and_then(|n: N| self.0.hi.checked_sub(&n)) {
+            Some(v) if v >= self.0.lo => {
+                if v == self.0.lo {
+                    self.0 = Extent::empty();
+                } else {
+                    self.0.hi = v - N::one();
+                }
+                Some(v)
+            }
+            _ => {
+                self.0 = Extent::empty();
+                None
+            }
+        }
+    }
 }
 
 impl<N: PrimInt> ExtentIter<N> {
@@ -184,22 +375,89 @@ impl<N: PrimInt> ExtentIter<N> {
     }
 }
 
+#[derive(Clone, Debug, Default)]
 pub struct ExtentRevIter<N: PrimInt>(Extent<N>);
 
 impl<N: PrimInt> Iterator for ExtentRevIter<N> {
     type Item = N;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.0.is_empty() {
-            None
+        let mut it = ExtentIter(self.0);
+        let v = it.next_back();
+        self.0 = it.0;
+        v
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        ExtentIter(self.0).size_hint()
+    }
+
+    fn count(self) -> usize {
+        ExtentIter(self.0).count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.0.lo()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let mut it = ExtentIter(self.0);
+        let v = it.nth_back(n);
+        self.0 = it.0;
+        v
+    }
+}
+
+impl<N: PrimInt> FusedIterator for ExtentRevIter<N> {}
+
+/// A strided iterator over an `Extent`, produced by `Extent::step_by`.
+/// Yields `lo, lo+step, lo+2*step, ...` up to and including any value `<=
+/// hi`, stopping cleanly (rather than panicking) if a stride would
+/// overflow `N`.
+#[derive(Clone, Debug, Default)]
+pub struct ExtentStepIter<N: PrimInt> {
+    extent: Extent<N>,
+    step: N,
+}
+
+impl<N: PrimInt> Iterator for ExtentStepIter<N> {
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.extent.is_empty() {
+            return None;
+        }
+        let v = self.extent.lo;
+        match v.checked_add(&self.step) {
+            Some(next) if next <= self.extent.hi => self.extent.lo = next,
+            _ => self.extent = Extent::empty(),
+        }
+        Some(v)
+    }
+}
+
+impl<N: PrimInt> DoubleEndedIterator for ExtentStepIter<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.extent.is_empty() {
+            return None;
+        }
+        // The last element actually reachable by striding from `lo` is not
+        // `hi` but the aligned top `lo + ((hi-lo)/step)*step`; anchoring
+        // here (rather than decrementing from `hi`) is what keeps `next`
+        // and `next_back` enumerating the same sequence from either end.
+        let span = self.extent.hi - self.extent.lo;
+        let top = self.extent.lo + (span / self.step) * self.step;
+        if top == self.extent.lo {
+            self.extent = Extent::empty();
         } else {
-            let v = self.0.hi;
-            self.0.hi = self.0.hi - N::one();
-            Some(v)
+            self.extent.hi = top - self.step;
         }
+        Some(top)
     }
 }
 
+impl<N: PrimInt> FusedIterator for ExtentStepIter<N> {}
+
 // std::ops::Range is an exclusive range. Extent is inclusive,
 // so we subtract one from any nonempty std::ops::Range.
 impl<N: PrimInt> From<Range<N>> for Extent<N> {
@@ -306,6 +564,39 @@ mod test {
 
         assert_eq!(ab.intersect(empty), empty);
         assert_eq!(empty.intersect(ab), empty);
+
+        assert!(!ab.is_disjoint(bc));
+        assert!(ab.is_disjoint(empty));
+        assert!(empty.is_disjoint(ab));
+
+        assert!(bb.is_subset(ab));
+        assert!(bb.is_subset(bc));
+        assert!(ab.is_subset(ac));
+        assert!(bc.is_subset(ac));
+        assert!(empty.is_subset(empty));
+        assert!(empty.is_subset(ab));
+
+        let ac_minus_bb_left = if a == b {
+            empty
+        } else {
+            Extent::from(a..=(b - N::one()))
+        };
+        let ac_minus_bb_right = if b == c {
+            empty
+        } else {
+            Extent::from((b + N::one())..=c)
+        };
+        assert_eq!(ac.difference(bb), (ac_minus_bb_left, ac_minus_bb_right));
+        assert_eq!(ab.difference(bc), (ac_minus_bb_left, empty));
+
+        assert_eq!(ac.difference(empty), (ac, empty));
+        assert_eq!(empty.difference(ac), (empty, empty));
+        assert_eq!(ac.difference(ac), (empty, empty));
+
+        assert_eq!(
+            ac.symmetric_difference(bb),
+            (ac_minus_bb_left, ac_minus_bb_right)
+        );
     }
 
     #[test]
@@ -341,4 +632,211 @@ mod test {
         let ev: Vec<u32> = Extent::empty().iter().collect();
         assert_eq!(ev, vec![]);
     }
+
+    #[test]
+    fn test_double_ended_iter() {
+        let mut it = Extent::from(0..=5).iter();
+        assert_eq!(it.next(), Some(0));
+        assert_eq!(it.next_back(), Some(5));
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.next_back(), Some(4));
+        assert_eq!(it.next(), Some(2));
+        assert_eq!(it.next_back(), Some(3));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        let mut single = Extent::from(3..=3).iter();
+        assert_eq!(single.next_back(), Some(3));
+        assert_eq!(single.next(), None);
+
+        let mut empty: ExtentIter<i32> = Extent::empty().iter();
+        assert_eq!(empty.next_back(), None);
+
+        // Regression: next()/next_back() must not overflow when the extent
+        // touches the type's endpoints.
+        let mut max = Extent::from(i32::MAX..=i32::MAX).iter();
+        assert_eq!(max.next(), Some(i32::MAX));
+        assert_eq!(max.next(), None);
+
+        let mut min = Extent::from(i32::MIN..=i32::MIN).iter();
+        assert_eq!(min.next_back(), Some(i32::MIN));
+        assert_eq!(min.next_back(), None);
+
+        let rv: Vec<_> = Extent::from(0..=5).iter().rev().collect();
+        assert_eq!(rv, vec![5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_size_hint_and_fast_paths() {
+        let it = Extent::from(0..=9).iter();
+        assert_eq!(it.size_hint(), (10, Some(10)));
+        assert_eq!(it.clone().count(), 10);
+        assert_eq!(it.clone().last(), Some(9));
+
+        let mut it = Extent::from(0..=9).iter();
+        assert_eq!(it.nth(3), Some(3));
+        assert_eq!(it.next(), Some(4));
+        assert_eq!(it.nth(100), None);
+        assert_eq!(it.next(), None);
+
+        let mut it = Extent::from(0..=9).iter();
+        assert_eq!(it.nth(9), Some(9));
+        assert_eq!(it.next(), None);
+
+        let rit = Extent::from(0..=9).iter().rev();
+        assert_eq!(rit.size_hint(), (10, Some(10)));
+        assert_eq!(rit.clone().count(), 10);
+        assert_eq!(rit.clone().last(), Some(0));
+
+        let mut rit = Extent::from(0..=9).iter().rev();
+        assert_eq!(rit.nth(3), Some(6));
+        assert_eq!(rit.next(), Some(5));
+        assert_eq!(rit.nth(100), None);
+        assert_eq!(rit.next(), None);
+
+        let empty: ExtentIter<i32> = Extent::empty().iter();
+        assert_eq!(empty.size_hint(), (0, Some(0)));
+        assert_eq!(empty.clone().count(), 0);
+        assert_eq!(empty.clone().last(), None);
+
+        // size_hint clamps into usize for extents too large to fit.
+        let huge = Extent::new(0u128, u128::MAX - 1).iter();
+        assert_eq!(huge.size_hint(), (usize::MAX, None));
+
+        // A full-width extent (element count of N::max_value() + 1, which
+        // doesn't fit in N itself) must not panic via Extent::len()'s
+        // internal overflow, and must still clamp correctly.
+        let full_width = Extent::new(0u64, u64::MAX).iter();
+        assert_eq!(full_width.size_hint(), (usize::MAX, None));
+
+        // A full-width extent of a type narrower than usize has an exact,
+        // non-overflowing count.
+        let full_u32: ExtentIter<u32> = Extent::new(0u32, u32::MAX).iter();
+        let expected = u32::MAX as usize + 1;
+        assert_eq!(full_u32.size_hint(), (expected, Some(expected)));
+    }
+
+    #[test]
+    fn test_step_by() {
+        let v: Vec<_> = Extent::from(0u8..=255).step_by(100).collect();
+        assert_eq!(v, vec![0, 100, 200]);
+
+        let v: Vec<_> = Extent::from(0..=10).step_by(3).collect();
+        assert_eq!(v, vec![0, 3, 6, 9]);
+
+        let v: Vec<_> = Extent::from(0..=9).step_by(3).collect();
+        assert_eq!(v, vec![0, 3, 6, 9]);
+
+        let v: Vec<_> = Extent::empty().step_by(1).collect();
+        assert_eq!(v, Vec::<i32>::new());
+
+        let v: Vec<_> = Extent::from(0..=10).step_by(3).rev().collect();
+        assert_eq!(v, vec![9, 6, 3, 0]);
+
+        // Mixing next()/next_back() must enumerate the same set as the
+        // forward sequence, not invent or skip elements.
+        let mut it = Extent::from(0..=10).step_by(3);
+        assert_eq!(it.next(), Some(0));
+        assert_eq!(it.next_back(), Some(9));
+        assert_eq!(it.next(), Some(3));
+        assert_eq!(it.next_back(), Some(6));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_step_by_zero_panics() {
+        let _ = Extent::from(0..=10).step_by(0);
+    }
+
+    #[test]
+    fn test_difference_boundaries() {
+        // Removing everything, including at the type's own min/max, must
+        // not underflow/overflow while clamping the leftover pieces.
+        let full = Extent::from(i32::MIN..=i32::MAX);
+        assert_eq!(full.difference(full), (Extent::empty(), Extent::empty()));
+
+        let lo_edge = Extent::from(i32::MIN..=5);
+        assert_eq!(
+            lo_edge.difference(Extent::from(i32::MIN..=0)),
+            (Extent::empty(), Extent::from(1..=5))
+        );
+
+        let hi_edge = Extent::from(0..=i32::MAX);
+        assert_eq!(
+            hi_edge.difference(Extent::from(10..=i32::MAX)),
+            (Extent::from(0..=9), Extent::empty())
+        );
+
+        assert_eq!(
+            Extent::from(0..=10).difference(Extent::from(3..=6)),
+            (Extent::from(0..=2), Extent::from(7..=10))
+        );
+    }
+
+    #[test]
+    fn test_disjoint() {
+        // `check_set_ops` always sorts its inputs so every tested pair
+        // overlaps at the middle point; exercise genuinely disjoint
+        // extents too, where `intersect`'s naive `lo.max(..)..=hi.min(..)`
+        // would otherwise swap into a bogus nonempty range.
+        let low = Extent::from(0..=2);
+        let high = Extent::from(5..=7);
+        let empty: Extent<i32> = Extent::empty();
+
+        assert_eq!(low.intersect(high), empty);
+        assert_eq!(high.intersect(low), empty);
+
+        assert!(low.is_disjoint(high));
+        assert!(high.is_disjoint(low));
+        assert!(!low.is_disjoint(low));
+
+        assert_eq!(low.difference(high), (low, empty));
+        assert_eq!(high.difference(low), (high, empty));
+
+        assert_eq!(low.symmetric_difference(high), (low, high));
+        assert_eq!(high.symmetric_difference(low), (low, high));
+
+        let adjacent = Extent::from(3..=4);
+        assert!(low.is_disjoint(adjacent));
+        assert_eq!(low.intersect(adjacent), empty);
+
+        let touching_min = Extent::from(i32::MIN..=(i32::MIN + 2));
+        let touching_max = Extent::from((i32::MAX - 2)..=i32::MAX);
+        assert!(touching_min.is_disjoint(touching_max));
+        assert_eq!(touching_min.intersect(touching_max), empty);
+        assert_eq!(touching_min.difference(touching_max), (touching_min, empty));
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let empty: Extent<i32> = Extent::empty();
+
+        // Disjoint with a gap: the gap itself (3, 4) must not appear.
+        assert_eq!(
+            Extent::from(0..=2).symmetric_difference(Extent::from(5..=7)),
+            (Extent::from(0..=2), Extent::from(5..=7))
+        );
+
+        // Partial overlap: only the two non-shared edges remain.
+        assert_eq!(
+            Extent::from(0..=5).symmetric_difference(Extent::from(3..=8)),
+            (Extent::from(0..=2), Extent::from(6..=8))
+        );
+
+        // One fully contains the other: the difference is just the
+        // containing extent's outer edges.
+        assert_eq!(
+            Extent::from(0..=10).symmetric_difference(Extent::from(3..=6)),
+            (Extent::from(0..=2), Extent::from(7..=10))
+        );
+
+        // Identical extents have nothing left over; against an empty
+        // extent, the nonempty one is left over whole.
+        let e = Extent::from(0..=5);
+        assert_eq!(e.symmetric_difference(e), (empty, empty));
+        assert_eq!(e.symmetric_difference(empty), (e, empty));
+        assert_eq!(empty.symmetric_difference(e), (e, empty));
+    }
 }